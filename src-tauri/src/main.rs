@@ -30,7 +30,13 @@ fn error_popup(msg: String, win: Window) {
 }
 
 mod data;
+// `notify-rust`'s action-button support is Linux/XDG-only, so this backend
+// only builds there; macOS and Windows fall back to the plain notification
+// (see `notifications::show_notification`).
+#[cfg(all(feature = "native-notifications", unix, not(target_os = "macos")))]
+mod notification_backend;
 mod notifications;
+mod titlebar;
 
 fn main() {
   let ctx = tauri::generate_context!();
@@ -53,6 +59,7 @@ fn main() {
     file: reminders_file,
     app_paths,
     scheduler: None,
+    app_handle: None,
     bundle_identifier: ctx.config().tauri.bundle.identifier.clone(),
   };
 
@@ -63,6 +70,9 @@ fn main() {
       notifications::get_groups,
       notifications::update_group,
       notifications::delete_group,
+      titlebar::minimize,
+      titlebar::maximize,
+      titlebar::toggle_fullscreen,
     ])
     .manage(Data(Mutex::new(instance)))
     .plugin(tauri_plugin_window_state::Builder::default().build())
@@ -105,7 +115,7 @@ fn main() {
   {
     let data: State<Data> = app.state();
     let mut x = data.0.lock().unwrap();
-    x.start();
+    x.start(app.handle());
   }
 
   app.run(|app_handle, e| match e {
@@ -135,41 +145,12 @@ fn create_window(app: &AppHandle) -> Window {
     .visible(false) // tauri_plugin_window_state reveals window
     .skip_taskbar(true);
 
-  #[cfg(target_os = "macos")]
-  let win = win
-    .transparent(true)
-    .title_bar_style(tauri::TitleBarStyle::Transparent);
+  let win = titlebar::configure_window(win);
 
   let win = win.build().expect("Unable to create window");
 
-  #[cfg(target_os = "macos")]
-  {
-    use cocoa::appkit::NSWindow;
-    let nsw = win.ns_window().unwrap() as cocoa::base::id;
-    unsafe {
-      nsw.setTitleVisibility_(cocoa::appkit::NSWindowTitleVisibility::NSWindowTitleHidden);
-
-      // set window to always be dark mode
-      use cocoa::appkit::NSAppearanceNameVibrantDark;
-      use objc::*;
-      let appearance: cocoa::base::id = msg_send![
-        class!(NSAppearance),
-        appearanceNamed: NSAppearanceNameVibrantDark
-      ];
-      let () = msg_send![nsw, setAppearance: appearance];
-
-      // set window background color
-      let bg_color = cocoa::appkit::NSColor::colorWithRed_green_blue_alpha_(
-        cocoa::base::nil,
-        // also used in App.svelte
-        255.0,
-        255.0,
-        255.0,
-        1.0,
-      );
-      nsw.setBackgroundColor_(bg_color);
-    }
-  }
+  titlebar::apply_platform_appearance(&win);
+
   win
 }
 
@@ -0,0 +1,46 @@
+// Tauri's bundled notification builder can't show action buttons on every
+// platform, so when the `native-notifications` feature is enabled we talk to
+// the platform notification service directly through `notify-rust` and route
+// the clicked action back into `Instance` instead.
+//
+// `notify-rust`'s `action()`/`wait_for_action()` only work against the
+// freedesktop/XDG backend, i.e. Linux - they don't exist on macOS or
+// Windows, so this module is only compiled in on `unix` minus `macos` (see
+// the `mod notification_backend` declaration in `main.rs`). Other platforms
+// fall back to `notifications::show_notification`'s plain-notification path,
+// which has no action buttons.
+use crate::notifications::{Data, Group};
+use notify_rust::{Notification, Timeout};
+use tauri::{AppHandle, Manager};
+
+pub fn show(app_handle: AppHandle, bundle_identifier: &str, group: &Group) -> Result<(), String> {
+  let mut notification = Notification::new();
+  notification
+    .appname(bundle_identifier)
+    .summary(&group.title)
+    .body(&group.description)
+    .timeout(Timeout::Never);
+  for action in &group.actions {
+    notification.action(&action.id, &action.label);
+  }
+
+  let handle = notification.show().map_err(|e| e.to_string())?;
+  let group_id = group.id.clone();
+  std::thread::spawn(move || {
+    handle.wait_for_action(|action_id| {
+      if action_id == "__closed" {
+        return;
+      }
+      route_action(&app_handle, &group_id, action_id);
+    });
+  });
+  Ok(())
+}
+
+fn route_action(app_handle: &AppHandle, group_id: &str, action_id: &str) {
+  let data: tauri::State<Data> = app_handle.state();
+  let mut instance = data.0.lock().unwrap();
+  if let Err(e) = instance.handle_notification_action(group_id, action_id) {
+    eprintln!("Could not handle notification action: {}", e);
+  }
+}
@@ -1,6 +1,7 @@
 use crate::data::{to_json, AppPaths, RemindersFile};
 use async_cron_scheduler::{Job, JobId, Scheduler};
 use chrono::offset::Local;
+use chrono::{Datelike, TimeZone, Timelike};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -8,17 +9,178 @@ use std::str::FromStr;
 use std::sync::Mutex;
 use tauri::api::dialog;
 use tauri::api::notification::Notification;
-use tauri::{command, State};
+use tauri::{command, AppHandle, Manager, State};
 
-#[derive(Serialize, Deserialize)]
-pub enum Repeat {
-  #[serde(rename = "never")]
-  Never,
-  #[serde(rename = "daily")]
-  Daily,
+#[cfg(all(feature = "native-notifications", unix, not(target_os = "macos")))]
+use crate::notification_backend;
+
+/// A notification action button. `id` must follow the convention
+/// `handle_notification_action` understands: `"snooze:<minutes>"` re-fires
+/// the reminder once after that many minutes, and `"done"` suspends the
+/// group for the rest of the day. Buttons only render through the
+/// `native-notifications` backend, which is Linux/XDG-only (`notify-rust`'s
+/// action support doesn't exist on macOS or Windows) - everywhere else,
+/// including the plain `tauri::api::notification` fallback, has no action
+/// support, so `actions` is ignored there.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NotificationAction {
+  pub id: String,
+  pub label: String,
+}
+
+/// The "Snooze 10m" / "Done" buttons every new group gets unless the
+/// frontend supplies its own `actions`.
+fn default_actions() -> Vec<NotificationAction> {
+  vec![
+    NotificationAction {
+      id: "snooze:10".into(),
+      label: "Snooze 10m".into(),
+    },
+    NotificationAction {
+      id: "done".into(),
+      label: "Done".into(),
+    },
+  ]
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub enum Weekday {
+  #[serde(rename = "sun")]
+  Sun,
+  #[serde(rename = "mon")]
+  Mon,
+  #[serde(rename = "tue")]
+  Tue,
+  #[serde(rename = "wed")]
+  Wed,
+  #[serde(rename = "thu")]
+  Thu,
+  #[serde(rename = "fri")]
+  Fri,
+  #[serde(rename = "sat")]
+  Sat,
+}
+impl Weekday {
+  fn cron_field(self) -> &'static str {
+    match self {
+      Weekday::Sun => "SUN",
+      Weekday::Mon => "MON",
+      Weekday::Tue => "TUE",
+      Weekday::Wed => "WED",
+      Weekday::Thu => "THU",
+      Weekday::Fri => "FRI",
+      Weekday::Sat => "SAT",
+    }
+  }
 }
 
+/// A typed recurrence pattern for a `Group`. `to_cron()` turns it into the
+/// cron expression (`sec min hour day-of-month month day-of-week`, plus a
+/// trailing year field for `Once`) that `Job::cron_schedule` expects, so a bad time-of-day string is rejected
+/// up front instead of surfacing as a cron parse error in a dialog popup.
+/// `Custom` is the escape hatch for anything the other variants can't
+/// express, and is also what an older reminders file's raw `cron` string
+/// deserializes into.
 #[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Recurrence {
+  Once { at: u64 },
+  Daily { time: String },
+  Weekly { days: Vec<Weekday>, time: String },
+  EveryNHours { n: u32 },
+  Custom(String),
+}
+impl Recurrence {
+  pub fn to_cron(&self) -> Result<String, String> {
+    match self {
+      Recurrence::Once { at } => {
+        // Pin the year too (the `cron` crate accepts an optional 7th field)
+        // so this is a genuine one-shot: without it, the day/month alone
+        // would recur every year.
+        let date = Local.timestamp(*at as i64, 0);
+        Ok(format!(
+          "{} {} {} {} {} * {}",
+          date.second(),
+          date.minute(),
+          date.hour(),
+          date.day(),
+          date.month(),
+          date.year()
+        ))
+      }
+      Recurrence::Daily { time } => {
+        let (hour, minute) = parse_time(time)?;
+        Ok(format!("0 {} {} * * *", minute, hour))
+      }
+      Recurrence::Weekly { days, time } => {
+        if days.is_empty() {
+          throw!("Weekly recurrence needs at least one day");
+        }
+        let (hour, minute) = parse_time(time)?;
+        let days = days
+          .iter()
+          .map(|day| day.cron_field())
+          .collect::<Vec<_>>()
+          .join(",");
+        Ok(format!("0 {} {} * * {}", minute, hour, days))
+      }
+      Recurrence::EveryNHours { n } => {
+        if *n == 0 {
+          throw!("EveryNHours must be at least 1");
+        }
+        // `*/n` only gives even spacing when `n` divides 24 - otherwise the
+        // hour field resets to 0 at midnight and leaves an uneven gap (e.g.
+        // `*/5` would fire at 0, 5, 10, 15, 20, then again at 0). Snap to the
+        // nearest divisor instead of rejecting outright, so a group
+        // persisted with an old, unevenly-spaced `n` keeps firing rather
+        // than permanently losing its job on load.
+        let aligned = nearest_divisor_of_24(*n);
+        if aligned != *n {
+          println!(
+            "EveryNHours({}) doesn't evenly divide 24; using {} instead so spacing stays even",
+            n, aligned
+          );
+        }
+        let hours = (0..24)
+          .step_by(aligned as usize)
+          .map(|hour| hour.to_string())
+          .collect::<Vec<_>>()
+          .join(",");
+        Ok(format!("0 0 {} * * *", hours))
+      }
+      Recurrence::Custom(cron) => Ok(cron.clone()),
+    }
+  }
+}
+
+// Picks the divisor of 24 genuinely closest to `n`, not just the largest one
+// `<= n` (that's a floor, and skews every non-divisor down - e.g. n=11 would
+// floor to 8, three hours off, when 12 is one hour off). Ties break toward
+// the smaller divisor, which is the *shorter* interval - so a group still
+// fires at least as often as was asked for instead of less.
+fn nearest_divisor_of_24(n: u32) -> u32 {
+  const DIVISORS_OF_24: [u32; 8] = [1, 2, 3, 4, 6, 8, 12, 24];
+  DIVISORS_OF_24
+    .iter()
+    .copied()
+    .min_by_key(|divisor| {
+      let distance = (*divisor as i32 - n as i32).abs();
+      (distance, *divisor as i32)
+    })
+    .unwrap_or(1)
+}
+
+fn parse_time(time: &str) -> Result<(u32, u32), String> {
+  let mut parts = time.splitn(2, ':');
+  let hour = parts.next().and_then(|h| h.parse::<u32>().ok());
+  let minute = parts.next().and_then(|m| m.parse::<u32>().ok());
+  match (hour, minute) {
+    (Some(hour), Some(minute)) if hour < 24 && minute < 60 => Ok((hour, minute)),
+    _ => throw!("Invalid time \"{}\", expected HH:MM", time),
+  }
+}
+
+#[derive(Serialize, Clone)]
 pub struct Group {
   pub title: String,
   pub description: String,
@@ -26,38 +188,227 @@ pub struct Group {
   pub id: String,
   #[serde(skip)]
   pub job_id: Option<JobId>,
-  pub cron: String,
+  pub recurrence: Recurrence,
   pub next_date: Option<u64>,
+  #[serde(default)]
+  pub actions: Vec<NotificationAction>,
+  #[serde(default)]
+  pub suspended_until: Option<u64>,
+  #[serde(default)]
+  pub last_fired: Option<u64>,
+}
+
+// Manual `Deserialize` so a reminders file written before `Recurrence`
+// existed (a flat `cron: String` field) keeps loading: it falls back to
+// wrapping that string in `Recurrence::Custom`.
+impl<'de> Deserialize<'de> for Group {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    struct GroupShadow {
+      title: String,
+      description: String,
+      enabled: bool,
+      id: String,
+      recurrence: Option<Recurrence>,
+      cron: Option<String>,
+      next_date: Option<u64>,
+      #[serde(default)]
+      actions: Vec<NotificationAction>,
+      #[serde(default)]
+      suspended_until: Option<u64>,
+      #[serde(default)]
+      last_fired: Option<u64>,
+    }
+    let shadow = GroupShadow::deserialize(deserializer)?;
+    let recurrence = match shadow.recurrence {
+      Some(recurrence) => recurrence,
+      None => match shadow.cron {
+        Some(cron) => Recurrence::Custom(cron),
+        None => return Err(serde::de::Error::missing_field("recurrence")),
+      },
+    };
+    Ok(Group {
+      title: shadow.title,
+      description: shadow.description,
+      enabled: shadow.enabled,
+      id: shadow.id,
+      job_id: None,
+      recurrence,
+      next_date: shadow.next_date,
+      actions: shadow.actions,
+      suspended_until: shadow.suspended_until,
+      last_fired: shadow.last_fired,
+    })
+  }
 }
+
 impl Group {
-  pub fn create_job(&mut self, scheduler: &mut Scheduler<Local>, a: String) -> Result<(), String> {
+  pub fn create_job(
+    &mut self,
+    scheduler: &mut Scheduler<Local>,
+    app_handle: AppHandle,
+    bundle_identifier: String,
+  ) -> Result<(), String> {
     if self.enabled {
-      let c = match cron::Schedule::from_str(&self.cron) {
+      let cron = self.recurrence.to_cron()?;
+      let c = match cron::Schedule::from_str(&cron) {
         Ok(c) => c,
         Err(e) => throw!("Invalid schedule: {}", e),
       };
+      self.next_date = c.upcoming(Local).next().map(|date| date.timestamp() as u64);
       let job = Job::cron_schedule(c);
-      let group = self.clone();
+      let group_id = self.id.clone();
       let job_id = scheduler.insert(job, move |_id| {
-        let result = Notification::new(&a)
-          .title(&group.title)
-          .body(&group.description)
-          .show();
-        match result {
-          Ok(_) => println!("Showed notification"),
-          Err(e) => eprintln!("Could not show notification: {}", e),
-        }
+        fire_reminder(&app_handle, &group_id, &bundle_identifier);
       });
       self.job_id = Some(job_id);
-      println!("Created job \"{}\" at {}", self.title, self.cron);
+      println!("Created job \"{}\" at {}", self.title, cron);
+    } else {
+      self.next_date = None;
     }
     Ok(())
   }
+
+  pub fn recompute_next_date(&mut self) {
+    self.next_date = self
+      .recurrence
+      .to_cron()
+      .ok()
+      .and_then(|cron| cron::Schedule::from_str(&cron).ok())
+      .and_then(|schedule| schedule.upcoming(Local).next())
+      .map(|date| date.timestamp() as u64);
+  }
+
+  pub fn is_suspended(&self) -> bool {
+    match self.suspended_until {
+      Some(until) => (Local::now().timestamp() as u64) < until,
+      None => false,
+    }
+  }
+
+  pub fn suspend_for_today(&mut self) {
+    let tomorrow = (Local::now() + chrono::Duration::days(1))
+      .date()
+      .and_hms(0, 0, 0);
+    self.suspended_until = Some(tomorrow.timestamp() as u64);
+    println!("Disabled \"{}\" for the rest of the day", self.title);
+  }
+
+  /// Fires a single coalesced notification for any occurrences that were
+  /// missed while the app was asleep or fully quit, then advances
+  /// `last_fired` up to now so the same gap isn't reported twice.
+  pub fn catch_up(&mut self, bundle_identifier: &str) {
+    let now = Local::now();
+    let last_fired = match self.last_fired {
+      // Cold first run: nothing to catch up on, just start tracking from now.
+      None => {
+        self.last_fired = Some(now.timestamp() as u64);
+        return;
+      }
+      Some(last_fired) => last_fired,
+    };
+    if !self.enabled {
+      // Don't accumulate missed occurrences while disabled - otherwise
+      // re-enabling later would enumerate the entire disabled span and fire
+      // one giant catch-up toast for time it was intentionally off.
+      self.last_fired = Some(now.timestamp() as u64);
+      return;
+    }
+    let schedule = match self
+      .recurrence
+      .to_cron()
+      .ok()
+      .and_then(|cron| cron::Schedule::from_str(&cron).ok())
+    {
+      Some(schedule) => schedule,
+      None => return,
+    };
+    let since = Local.timestamp(last_fired as i64, 0);
+    let missed = count_missed(&schedule, since, now);
+    if missed > 0 {
+      let body = format!(
+        "{} reminder{} while you were away",
+        missed,
+        if missed == 1 { "" } else { "s" }
+      );
+      let result = Notification::new(bundle_identifier)
+        .title(&self.title)
+        .body(&body)
+        .show();
+      match result {
+        Ok(_) => println!("Showed catch-up notification for \"{}\"", self.title),
+        Err(e) => eprintln!("Could not show catch-up notification: {}", e),
+      }
+    }
+    self.last_fired = Some(now.timestamp() as u64);
+  }
+}
+
+/// Counts the occurrences of `schedule` in `(since, now]`.
+fn count_missed(
+  schedule: &cron::Schedule,
+  since: chrono::DateTime<Local>,
+  now: chrono::DateTime<Local>,
+) -> usize {
+  schedule.after(&since).take_while(|date| *date <= now).count()
+}
+
+// Looks up the live group state by id (instead of relying on the clone the
+// scheduler closure was created with) so a toggle or edit made through
+// `update_group` is respected without having to recreate the job, then shows
+// the reminder through whichever notification backend is compiled in.
+fn fire_reminder(app_handle: &AppHandle, group_id: &str, bundle_identifier: &str) {
+  let data: State<Data> = app_handle.state();
+  let mut instance = data.0.lock().unwrap();
+  let group = match instance.file.groups.iter_mut().find(|g| &g.id == group_id) {
+    Some(group) => group,
+    None => return,
+  };
+  if !group.enabled {
+    println!("Skipping disabled reminder \"{}\"", group.title);
+    return;
+  }
+  group.recompute_next_date();
+  if group.is_suspended() {
+    println!("Skipping suspended reminder \"{}\"", group.title);
+    return;
+  }
+  group.last_fired = Some(Local::now().timestamp() as u64);
+  let group = group.clone();
+  instance.emit_update();
+  if let Err(e) = instance.save() {
+    eprintln!("Could not save reminders file: {}", e);
+  }
+  drop(instance);
+  if let Err(e) = show_notification(app_handle, bundle_identifier, &group) {
+    eprintln!("Could not show notification: {}", e);
+  }
+}
+
+#[cfg(all(feature = "native-notifications", unix, not(target_os = "macos")))]
+fn show_notification(app_handle: &AppHandle, bundle_identifier: &str, group: &Group) -> Result<(), String> {
+  notification_backend::show(app_handle.clone(), bundle_identifier, group)
+}
+
+// Covers both "the feature is off" and "the feature is on but this platform
+// has no native-notifications backend yet" (macOS, Windows): falls back to
+// the plain notification, which has no action-button support.
+#[cfg(not(all(feature = "native-notifications", unix, not(target_os = "macos"))))]
+fn show_notification(_app_handle: &AppHandle, bundle_identifier: &str, group: &Group) -> Result<(), String> {
+  Notification::new(bundle_identifier)
+    .title(&group.title)
+    .body(&group.description)
+    .show()
+    .map_err(|e| e.to_string())
 }
 
 pub struct Instance {
   pub file: RemindersFile,
   pub scheduler: Option<Scheduler<Local>>,
+  pub app_handle: Option<AppHandle>,
   pub app_paths: AppPaths,
   pub bundle_identifier: String,
 }
@@ -65,17 +416,33 @@ impl Instance {
   pub fn save(&self) -> Result<(), String> {
     self.file.save(&self.app_paths)
   }
-  pub fn add_group(&mut self, mut group: Group) -> Result<(), String> {
-    match &mut self.scheduler {
-      Some(scheduler) => {
-        group.create_job(scheduler, self.bundle_identifier.clone())?;
-        self.file.groups.push(group);
-      }
-      None => {
-        self.file.groups.push(group);
-        self.start();
-      }
+
+  /// Broadcasts the current group list (with freshly computed `next_date`s)
+  /// so the frontend can render a live countdown without polling
+  /// `get_groups`.
+  fn emit_update(&self) {
+    let app_handle = match &self.app_handle {
+      Some(app_handle) => app_handle,
+      None => return,
     };
+    if let Err(e) = app_handle.emit_all("reminders-updated", &self.file.groups) {
+      eprintln!("Could not emit reminders-updated: {}", e);
+    }
+  }
+
+  pub fn add_group(&mut self, mut group: Group) -> Result<(), String> {
+    // `self.app_handle` is only ever `Some` once `start()` has run, which
+    // also sets `self.scheduler` - so if the scheduler isn't running yet
+    // there's no handle to create a job with either. That can't happen once
+    // the app has booted (`main.rs` calls `start()` right after `build()`,
+    // before any command can reach this path), so just skip job creation
+    // rather than reaching for a handle we don't have.
+    if let Some(scheduler) = &mut self.scheduler {
+      let app_handle = self.app_handle.clone().expect("app handle not ready");
+      group.create_job(scheduler, app_handle, self.bundle_identifier.clone())?;
+    }
+    self.file.groups.push(group);
+    self.emit_update();
     Ok(())
   }
   pub fn generate_id(&self) -> String {
@@ -97,6 +464,7 @@ impl Instance {
       Some(scheduler) => scheduler,
       None => {
         self.file.groups.remove(index);
+        self.emit_update();
         return;
       }
     };
@@ -105,15 +473,23 @@ impl Instance {
       None => {}
     };
     self.file.groups.remove(index);
+    self.emit_update();
   }
-  pub fn start(&mut self) {
+  pub fn start(&mut self, app_handle: AppHandle) {
     let bundle_identifier = self.bundle_identifier.clone();
 
+    for group in &mut self.file.groups {
+      group.catch_up(&bundle_identifier);
+    }
+    if let Err(e) = self.save() {
+      eprintln!("Could not save reminders file: {}", e);
+    }
+
     let (mut scheduler, sched_service) = Scheduler::<Local>::launch(tokio::time::sleep);
 
     let mut errors = Vec::new();
     for group in &mut self.file.groups {
-      match group.create_job(&mut scheduler, bundle_identifier.clone()) {
+      match group.create_job(&mut scheduler, app_handle.clone(), bundle_identifier.clone()) {
         Ok(_) => {}
         Err(e) => errors.push(e),
       };
@@ -126,8 +502,61 @@ impl Instance {
     }
 
     self.scheduler = Some(scheduler);
+    self.app_handle = Some(app_handle);
     tauri::async_runtime::spawn(sched_service);
   }
+
+  /// Routes a notification action button (e.g. "Snooze 10m" or "Done") back
+  /// into the scheduler. Called from the native notification backend once an
+  /// action has been clicked.
+  pub fn handle_notification_action(&mut self, group_id: &str, action_id: &str) -> Result<(), String> {
+    let index = match self.file.groups.iter().position(|g| g.id == group_id) {
+      Some(index) => index,
+      None => throw!("Unknown group: {}", group_id),
+    };
+    if let Some(offset) = action_id.strip_prefix("snooze:") {
+      let minutes: u64 = match offset.parse() {
+        Ok(minutes) => minutes,
+        Err(_) => throw!("Invalid snooze action: {}", action_id),
+      };
+      self.snooze_group(index, minutes)?;
+    } else if action_id == "done" {
+      self.file.groups[index].suspend_for_today();
+    }
+    self.emit_update();
+    self.save()
+  }
+
+  fn snooze_group(&mut self, index: usize, minutes: u64) -> Result<(), String> {
+    let scheduler = match &mut self.scheduler {
+      Some(scheduler) => scheduler,
+      None => throw!("Scheduler not running"),
+    };
+    let app_handle = match &self.app_handle {
+      Some(app_handle) => app_handle.clone(),
+      None => throw!("App handle not ready"),
+    };
+    let group = self.file.groups[index].clone();
+    let group_id = group.id.clone();
+    let bundle_identifier = self.bundle_identifier.clone();
+    // `async_cron_scheduler::Job` only exposes cron-based constructors, so a
+    // one-shot "fire once in N minutes" job is expressed the same way
+    // `Recurrence::Once` pins a genuine one-shot: a cron expression with the
+    // exact future sec/min/hour/day/month/year filled in. Separate from the
+    // group's recurring job, so the regular schedule is left untouched.
+    let fire_at = (Local::now() + chrono::Duration::minutes(minutes as i64)).timestamp() as u64;
+    let cron = Recurrence::Once { at: fire_at }.to_cron()?;
+    let schedule = match cron::Schedule::from_str(&cron) {
+      Ok(schedule) => schedule,
+      Err(e) => throw!("Invalid snooze schedule: {}", e),
+    };
+    let job = Job::cron_schedule(schedule);
+    scheduler.insert(job, move |_id| {
+      fire_reminder(&app_handle, &group_id, &bundle_identifier);
+    });
+    println!("Snoozed \"{}\" for {} minutes", group.title, minutes);
+    Ok(())
+  }
 }
 
 pub struct Data(pub Mutex<Instance>);
@@ -142,6 +571,9 @@ pub async fn get_groups(data: State<'_, Data>) -> Result<Value, String> {
 pub async fn new_group(mut group: Group, data: State<'_, Data>) -> Result<Value, String> {
   let mut data = data.0.lock().unwrap();
   group.id = data.generate_id();
+  if group.actions.is_empty() {
+    group.actions = default_actions();
+  }
   data.add_group(group)?;
   data.save()?;
   to_json(&data.file.groups)
@@ -154,3 +586,110 @@ pub async fn delete_group(index: usize, data: State<'_, Data>) -> Result<Value,
   data.save()?;
   to_json(&data.file.groups)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn to_cron_covers_every_variant_except_once() {
+    // `Once` depends on the local time zone; it has its own test below.
+    let cases = vec![
+      (Recurrence::Daily { time: "08:05".into() }, "0 5 8 * * *".to_string()),
+      (
+        Recurrence::Weekly {
+          days: vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+          time: "09:00".into(),
+        },
+        "0 0 9 * * MON,WED,FRI".to_string(),
+      ),
+      (
+        Recurrence::EveryNHours { n: 6 },
+        "0 0 0,6,12,18 * * *".to_string(),
+      ),
+      (
+        Recurrence::Custom("0 */15 * * * *".into()),
+        "0 */15 * * * *".to_string(),
+      ),
+    ];
+    for (recurrence, expected) in cases {
+      assert_eq!(recurrence.to_cron().unwrap(), expected);
+    }
+  }
+
+  #[test]
+  fn once_pins_the_year_so_it_fires_exactly_once() {
+    // Regression test: the day/month alone would recur every year, and a
+    // `Schedule` without the year field can't express a true one-shot.
+    let at = 1_700_000_000u64;
+    let date = Local.timestamp(at as i64, 0);
+    let expected = format!(
+      "{} {} {} {} {} * {}",
+      date.second(),
+      date.minute(),
+      date.hour(),
+      date.day(),
+      date.month(),
+      date.year()
+    );
+    let cron = Recurrence::Once { at }.to_cron().unwrap();
+    assert_eq!(cron, expected);
+    assert!(cron.ends_with(&date.year().to_string()));
+
+    let schedule = cron::Schedule::from_str(&cron).unwrap();
+    let one_year_later = date + chrono::Duration::days(366);
+    assert_eq!(count_missed(&schedule, date - chrono::Duration::seconds(1), one_year_later), 1);
+  }
+
+  #[test]
+  fn every_n_hours_snaps_non_divisors_to_even_spacing() {
+    // n=11 is closer to 12 than to 8 - a floor would wrongly snap to 8.
+    assert_eq!(
+      Recurrence::EveryNHours { n: 11 }.to_cron().unwrap(),
+      "0 0 0,12 * * *"
+    );
+    // n=5 is equidistant from 4 and 6; ties break toward the smaller divisor
+    // (the shorter, more frequent interval).
+    assert_eq!(
+      Recurrence::EveryNHours { n: 5 }.to_cron().unwrap(),
+      "0 0 0,4,8,12,16,20 * * *"
+    );
+    assert_eq!(
+      Recurrence::EveryNHours { n: 6 }.to_cron().unwrap(),
+      "0 0 0,6,12,18 * * *"
+    );
+    assert!(Recurrence::EveryNHours { n: 0 }.to_cron().is_err());
+  }
+
+  #[test]
+  fn nearest_divisor_of_24_picks_the_closer_neighbor_not_the_floor() {
+    assert_eq!(nearest_divisor_of_24(11), 12); // closer to 12 than 8
+    assert_eq!(nearest_divisor_of_24(9), 8); // closer to 8 than 12
+    assert_eq!(nearest_divisor_of_24(10), 8); // tie -> smaller (more frequent)
+    assert_eq!(nearest_divisor_of_24(6), 6); // exact divisor
+  }
+
+  #[test]
+  fn parse_time_rejects_out_of_range_values() {
+    assert!(parse_time("24:00").is_err());
+    assert!(parse_time("10:60").is_err());
+    assert!(parse_time("not-a-time").is_err());
+    assert_eq!(parse_time("09:30").unwrap(), (9, 30));
+  }
+
+  #[test]
+  fn count_missed_counts_occurrences_since_last_fired() {
+    let schedule = cron::Schedule::from_str("0 0 * * * * *").unwrap();
+    let since = Local.timestamp(1_700_000_000, 0); // 2023-11-14 22:13:20 UTC-ish
+    let now = since + chrono::Duration::hours(3);
+    // Hourly schedule over a 3 hour window: exactly 3 occurrences.
+    assert_eq!(count_missed(&schedule, since, now), 3);
+  }
+
+  #[test]
+  fn count_missed_is_zero_when_nothing_elapsed() {
+    let schedule = cron::Schedule::from_str("0 0 * * * * *").unwrap();
+    let now = Local.timestamp(1_700_000_000, 0);
+    assert_eq!(count_missed(&schedule, now, now), 0);
+  }
+}
@@ -0,0 +1,60 @@
+// Frameless window controls shared by all platforms: native decorations are
+// turned off everywhere and the frontend draws its own titlebar, using a
+// `data-tauri-drag-region` element for the draggable area and these commands
+// for the window-control buttons. Platform appearance quirks (currently just
+// macOS vibrancy) stay behind this module instead of leaking into `main.rs`.
+use tauri::{command, Window, WindowBuilder};
+
+pub fn configure_window<'a>(builder: WindowBuilder<'a>) -> WindowBuilder<'a> {
+  builder.decorations(false)
+}
+
+pub fn apply_platform_appearance(window: &Window) {
+  #[cfg(target_os = "macos")]
+  {
+    use cocoa::appkit::{NSAppearanceNameVibrantDark, NSColor, NSWindow, NSWindowTitleVisibility};
+    use cocoa::base::{id, nil};
+    use objc::*;
+
+    let nsw = window.ns_window().unwrap() as id;
+    unsafe {
+      nsw.setTitleVisibility_(NSWindowTitleVisibility::NSWindowTitleHidden);
+
+      // set window to always be dark mode
+      let appearance: id = msg_send![
+        class!(NSAppearance),
+        appearanceNamed: NSAppearanceNameVibrantDark
+      ];
+      let () = msg_send![nsw, setAppearance: appearance];
+
+      // set window background color, also used in App.svelte
+      let bg_color = NSColor::colorWithRed_green_blue_alpha_(nil, 255.0, 255.0, 255.0, 1.0);
+      nsw.setBackgroundColor_(bg_color);
+    }
+  }
+  #[cfg(not(target_os = "macos"))]
+  let _ = window;
+}
+
+#[command]
+pub fn minimize(window: Window) -> Result<(), String> {
+  window.minimize().map_err(|e| e.to_string())
+}
+
+#[command]
+pub fn maximize(window: Window) -> Result<(), String> {
+  let is_maximized = window.is_maximized().map_err(|e| e.to_string())?;
+  if is_maximized {
+    window.unmaximize().map_err(|e| e.to_string())
+  } else {
+    window.maximize().map_err(|e| e.to_string())
+  }
+}
+
+#[command]
+pub fn toggle_fullscreen(window: Window) -> Result<(), String> {
+  let is_fullscreen = window.is_fullscreen().map_err(|e| e.to_string())?;
+  window
+    .set_fullscreen(!is_fullscreen)
+    .map_err(|e| e.to_string())
+}